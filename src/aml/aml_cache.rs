@@ -0,0 +1,78 @@
+use near_sdk::{env, AccountId};
+
+use super::{CategoryRisk, AML};
+
+impl AML {
+    /// Returns the cached verdict for `address` if one was stored within the
+    /// last `max_age_ns` nanoseconds, measured against the current
+    /// `env::block_timestamp`. Returns `None` when there is no cached entry
+    /// or it's stale, in which case the caller should fall back to
+    /// [`AML::query_risk`] and store the result with [`AML::cache_verdict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// let address: AccountId = AccountId::new_unchecked("user.near".to_string());
+    /// assert!(aml.cached_verdict(&address, 60_000_000_000).is_none());
+    /// ```
+    pub fn cached_verdict(&self, address: &AccountId, max_age_ns: u64) -> Option<Vec<CategoryRisk>> {
+        let (category_risks, checked_at) = self.verdict_cache.get(address)?;
+        let now = env::block_timestamp();
+        if now.saturating_sub(checked_at) <= max_age_ns {
+            Some(category_risks)
+        } else {
+            None
+        }
+    }
+
+    /// Stores `category_risks` for `address`, timestamped with the current
+    /// `env::block_timestamp`, for later lookup through [`AML::cached_verdict`].
+    pub fn cache_verdict(&mut self, address: AccountId, category_risks: Vec<CategoryRisk>) {
+        self.verdict_cache
+            .insert(&address, &(category_risks, env::block_timestamp()));
+    }
+
+    /// Forces the next lookup for `address` to miss the cache.
+    pub fn invalidate(&mut self, address: &AccountId) {
+        self.verdict_cache.remove(address);
+    }
+
+    /// Forces the next lookup for every address to miss the cache.
+    pub fn invalidate_all(&mut self) {
+        self.verdict_cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    use super::*;
+    use crate::aml::{Category, MAX_RISK_LEVEL};
+
+    fn set_block_timestamp(ns: u64) {
+        let context = VMContextBuilder::new().block_timestamp(ns).build();
+        testing_env!(context);
+    }
+
+    #[test]
+    fn cached_verdict_respects_max_age_boundary() {
+        set_block_timestamp(0);
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+        aml.cache_verdict(address.clone(), vec![(Category::Scam, 3)]);
+
+        set_block_timestamp(60_000_000_000);
+        assert!(aml.cached_verdict(&address, 60_000_000_000).is_some());
+
+        set_block_timestamp(60_000_000_001);
+        assert!(aml.cached_verdict(&address, 60_000_000_000).is_none());
+    }
+}
@@ -0,0 +1,97 @@
+use super::{AggregatePolicy, AmlManager, Category, CategoryRisk, AML};
+
+/// A legitimate provider response has at most one entry per [`Category`]
+/// variant (there are 20). Anything longer is malformed or adversarial
+/// input and isn't trusted to compute a weighted sum over.
+const MAX_CATEGORY_RISKS: usize = 20;
+
+impl AML {
+    /// Reduces `category_risks` to an allow/reject decision according to the
+    /// configured [`AggregatePolicy`].
+    pub fn verify_risk(&self, category_risks: &[CategoryRisk]) -> bool {
+        match self.get_policy() {
+            AggregatePolicy::AnyExceeds => category_risks.iter().all(|(category, risk_score)| {
+                // A category without its own configured threshold falls back to
+                // Category::All, the catch-all ceiling every AML is seeded with.
+                let accepted_risk_score = self
+                    .get_aml_conditions()
+                    .get(category)
+                    .or_else(|| self.get_aml_conditions().get(&Category::All));
+                match accepted_risk_score {
+                    Some(accepted_risk_score) => *risk_score <= accepted_risk_score,
+                    None => true,
+                }
+            }),
+            AggregatePolicy::MaxOf => {
+                let max_risk_score = category_risks
+                    .iter()
+                    .map(|(_, risk_score)| *risk_score)
+                    .max()
+                    .unwrap_or(0);
+                match self.get_aml_conditions().get(&Category::All) {
+                    Some(accepted_risk_score) => max_risk_score <= accepted_risk_score,
+                    None => true,
+                }
+            }
+            AggregatePolicy::WeightedSum { weights, limit } => {
+                if category_risks.len() > MAX_CATEGORY_RISKS {
+                    return false;
+                }
+                let total = category_risks
+                    .iter()
+                    .map(|(category, risk_score)| {
+                        let weight = weights
+                            .iter()
+                            .find(|(weighted_category, _)| weighted_category == category)
+                            .map(|(_, weight)| *weight)
+                            .unwrap_or(1) as u32;
+                        weight * (*risk_score as u32)
+                    })
+                    // Providers are untrusted; saturate instead of wrapping so
+                    // an inflated response can't wrap the sum back under
+                    // `limit` and slip through as low-risk.
+                    .fold(0u32, u32::saturating_add);
+                total <= *limit
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::accounts;
+
+    use super::*;
+    use crate::aml::MAX_RISK_LEVEL;
+
+    #[test]
+    fn any_exceeds_falls_back_to_category_all_threshold() {
+        let mut aml = AML::new(accounts(0), 5);
+        // No entry for Category::Scam, so the Category::All ceiling applies.
+        assert!(aml.verify_risk(&[(Category::Scam, 5)]));
+        assert!(!aml.verify_risk(&[(Category::Scam, 6)]));
+    }
+
+    #[test]
+    fn weighted_sum_rejects_oversized_provider_responses() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        aml.set_policy(AggregatePolicy::weighted_sum(
+            &[(Category::Scam, u8::MAX)],
+            u32::MAX,
+        ));
+        // More entries than Category has variants can only come from a
+        // malformed or adversarial provider response.
+        let category_risks: Vec<CategoryRisk> = (0..MAX_CATEGORY_RISKS + 1)
+            .map(|_| (Category::Scam, u8::MAX))
+            .collect();
+        assert!(!aml.verify_risk(&category_risks));
+    }
+
+    #[test]
+    fn weighted_sum_rejects_once_limit_exceeded() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        aml.set_policy(AggregatePolicy::weighted_sum(&[(Category::Scam, 3)], 20));
+        assert!(aml.verify_risk(&[(Category::Scam, 6)]));
+        assert!(!aml.verify_risk(&[(Category::Scam, 7)]));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use near_sdk::{env, AccountId, Promise, PromiseResult};
+
+use super::{
+    ext_aml, AmlManager, Category, CategoryRisk, ProviderStrategy, AML, GAS_FOR_GET_ADDRESS,
+};
+
+impl AML {
+    /// Fans a risk query out to every configured provider and joins the
+    /// promises so a single callback can resolve all of them together with
+    /// [`AML::resolve_provider_results`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// let address: AccountId = AccountId::new_unchecked("user.near".to_string());
+    /// let _promise = aml.query_risk_all_providers(address);
+    /// ```
+    pub fn query_risk_all_providers(&self, address: AccountId) -> Promise {
+        let mut providers = self.get_providers().into_iter();
+        let first_provider = providers
+            .next()
+            .unwrap_or_else(|| env::panic_str("ERR_NO_AML_PROVIDER_CONFIGURED"));
+
+        providers.fold(
+            ext_aml::ext(first_provider)
+                .with_static_gas(GAS_FOR_GET_ADDRESS)
+                .get_address(address.to_string()),
+            |joined, provider| {
+                joined.and(
+                    ext_aml::ext(provider)
+                        .with_static_gas(GAS_FOR_GET_ADDRESS)
+                        .get_address(address.to_string()),
+                )
+            },
+        )
+    }
+
+    /// Reads every promise result scheduled by a preceding
+    /// [`AML::query_risk_all_providers`] call, treating a failed or
+    /// unparsable provider response as "no data" rather than aborting.
+    pub fn read_provider_promise_results(&self) -> Vec<Option<Vec<CategoryRisk>>> {
+        (0..env::promise_results_count())
+            .map(|i| match env::promise_result(i) {
+                PromiseResult::Successful(bytes) => near_sdk::serde_json::from_slice(&bytes).ok(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Reduces the per-provider results of [`AML::query_risk_all_providers`]
+    /// to a verdict according to the configured [`ProviderStrategy`].
+    pub fn resolve_provider_results(
+        &self,
+        category_risks_by_provider: Vec<Option<Vec<CategoryRisk>>>,
+    ) -> bool {
+        match self.get_strategy() {
+            ProviderStrategy::FirstAvailable => {
+                let category_risks = category_risks_by_provider
+                    .into_iter()
+                    .flatten()
+                    .next()
+                    .unwrap_or_else(|| env::panic_str("ERR_ALL_AML_PROVIDERS_FAILED"));
+                self.verify_risk(&category_risks)
+            }
+            ProviderStrategy::Quorum { n } => {
+                let responses: Vec<Vec<CategoryRisk>> =
+                    category_risks_by_provider.into_iter().flatten().collect();
+                // Fail closed: without at least `n` actual responses there aren't
+                // enough votes to ever reach quorum either way, so a provider
+                // outage must reject rather than silently clear the address.
+                if (responses.len() as u8) < *n {
+                    return false;
+                }
+
+                let mut exceeded_votes: HashMap<Category, u8> = HashMap::new();
+                for category_risks in responses {
+                    for (category, risk_score) in category_risks {
+                        // Match the Category::All fallback used by AnyExceeds/MaxOf
+                        // so the same data isn't accepted under one policy and
+                        // rejected under Quorum purely for lack of this fallback.
+                        let accepted_risk_score = self
+                            .get_aml_conditions()
+                            .get(&category)
+                            .or_else(|| self.get_aml_conditions().get(&Category::All))
+                            .unwrap_or(0);
+                        if risk_score > accepted_risk_score {
+                            *exceeded_votes.entry(category).or_insert(0) += 1;
+                        }
+                    }
+                }
+                !exceeded_votes.values().any(|votes| *votes >= *n)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::accounts;
+
+    use super::*;
+    use crate::aml::MAX_RISK_LEVEL;
+
+    #[test]
+    fn quorum_rejects_when_too_few_providers_responded() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        aml.set_strategy(ProviderStrategy::Quorum { n: 2 });
+
+        // Only one of three providers came back, so quorum can never be
+        // reached either way and the address must be rejected.
+        let results = vec![Some(vec![(Category::Scam, 1)]), None, None];
+        assert!(!aml.resolve_provider_results(results));
+    }
+
+    #[test]
+    fn quorum_accepts_when_enough_providers_agree_below_threshold() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        aml.set_strategy(ProviderStrategy::Quorum { n: 2 });
+
+        let results = vec![
+            Some(vec![(Category::Scam, 1)]),
+            Some(vec![(Category::Scam, 1)]),
+            None,
+        ];
+        assert!(aml.resolve_provider_results(results));
+    }
+
+    #[test]
+    fn quorum_falls_back_to_category_all_threshold() {
+        // Only Category::All is seeded by AML::new, so an unconfigured
+        // category must still be judged against its ceiling instead of 0.
+        let mut aml = AML::new(accounts(0), 5);
+        aml.set_strategy(ProviderStrategy::Quorum { n: 2 });
+
+        let results = vec![
+            Some(vec![(Category::Scam, 5)]),
+            Some(vec![(Category::Scam, 5)]),
+        ];
+        assert!(aml.resolve_provider_results(results));
+
+        let results = vec![
+            Some(vec![(Category::Scam, 6)]),
+            Some(vec![(Category::Scam, 6)]),
+        ];
+        assert!(!aml.resolve_provider_results(results));
+    }
+}
@@ -0,0 +1,195 @@
+use near_sdk::{ext_contract, require, AccountId, Gas, Promise, PromiseResult};
+
+use super::{CategoryRisk, AmlManager, AML};
+
+/// Gas reserved for the cross-contract call into the HAPI service account.
+pub const GAS_FOR_GET_ADDRESS: Gas = Gas(5_000_000_000_000);
+
+/// Gas reserved for resolving the HAPI service's response.
+pub const GAS_FOR_RESOLVE_RISK: Gas = Gas(5_000_000_000_000);
+
+/// The subset of the HAPI service account's interface this connector calls into.
+#[ext_contract(ext_aml)]
+pub trait ExtAml {
+    /// Returns every category the HAPI service has recorded for `address`,
+    /// paired with its risk score.
+    fn get_address(&self, address: String) -> Vec<CategoryRisk>;
+}
+
+impl AML {
+    /// Queries the configured HAPI service account for `address` and returns
+    /// the raw cross-contract `Promise`. Callers should chain their own
+    /// callback via `.then(...)` and resolve it with [`AML::assert_risk_promise_result`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// let address: AccountId = AccountId::new_unchecked("user.near".to_string());
+    /// let _promise = aml.query_risk(address);
+    /// ```
+    pub fn query_risk(&self, address: AccountId) -> Promise {
+        let (primary_provider, _) = self.get_aml();
+        ext_aml::ext(primary_provider)
+            .with_static_gas(GAS_FOR_GET_ADDRESS)
+            .get_address(address.to_string())
+    }
+
+    /// Short-circuits the HAPI query for addresses with a local override:
+    /// whitelisted addresses are always allowed, blacklisted addresses are
+    /// always rejected. Returns `None` when no override applies and the
+    /// caller should fall through to [`AML::query_risk`].
+    pub fn local_verdict(&self, address: &AccountId) -> Option<bool> {
+        if self.is_whitelisted(address) {
+            Some(true)
+        } else if self.is_blacklisted(address) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the result of a previously scheduled [`AML::query_risk`] promise
+    /// out of `env::promise_result`, reduces it to a verdict with
+    /// [`AML::verify_risk`] under the configured [`super::AggregatePolicy`],
+    /// caches the returned risks for `address`, and panics if the verdict is
+    /// a rejection.
+    ///
+    /// Intended to be called from a `#[private]` callback on the consuming
+    /// contract, e.g.:
+    ///
+    /// ```ignore
+    /// #[private]
+    /// pub fn query_risk_callback(&mut self, address: AccountId) -> bool {
+    ///     self.aml.assert_risk_promise_result(address)
+    /// }
+    /// ```
+    pub fn assert_risk_promise_result(&mut self, address: AccountId) -> bool {
+        require!(
+            near_sdk::env::promise_results_count() == 1,
+            "ERR_UNEXPECTED_PROMISE_RESULTS_COUNT"
+        );
+
+        let category_risks: Vec<CategoryRisk> = match near_sdk::env::promise_result(0) {
+            PromiseResult::Successful(bytes) => near_sdk::serde_json::from_slice(&bytes)
+                .unwrap_or_else(|_| near_sdk::env::panic_str("ERR_INVALID_AML_RESPONSE")),
+            _ => near_sdk::env::panic_str("ERR_AML_QUERY_FAILED"),
+        };
+
+        let is_allowed = self.verify_risk(&category_risks);
+        self.cache_verdict(address, category_risks);
+        require!(is_allowed, "ERR_ADDRESS_RISK_TOO_HIGH");
+        is_allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, RuntimeFeesConfig, VMConfig};
+
+    use super::*;
+    use crate::aml::{Category, MAX_RISK_LEVEL};
+
+    fn set_promise_results(results: Vec<PromiseResult>) {
+        let context = VMContextBuilder::new().build();
+        testing_env!(
+            context,
+            VMConfig::test(),
+            RuntimeFeesConfig::test(),
+            HashMap::new(),
+            results
+        );
+    }
+
+    #[test]
+    fn query_risk_does_not_panic_with_configured_provider() {
+        let aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let _promise = aml.query_risk(accounts(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_UNEXPECTED_PROMISE_RESULTS_COUNT")]
+    fn assert_risk_promise_result_requires_exactly_one_promise() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        set_promise_results(vec![
+            PromiseResult::Successful(b"[]".to_vec()),
+            PromiseResult::Successful(b"[]".to_vec()),
+        ]);
+        aml.assert_risk_promise_result(accounts(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_AML_QUERY_FAILED")]
+    fn assert_risk_promise_result_panics_when_promise_failed() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        set_promise_results(vec![PromiseResult::Failed]);
+        aml.assert_risk_promise_result(accounts(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_INVALID_AML_RESPONSE")]
+    fn assert_risk_promise_result_panics_on_unparsable_response() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        set_promise_results(vec![PromiseResult::Successful(b"not json".to_vec())]);
+        aml.assert_risk_promise_result(accounts(1));
+    }
+
+    #[test]
+    fn assert_risk_promise_result_allows_and_caches_low_risk() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+        let body = near_sdk::serde_json::to_vec(&vec![(Category::Scam, 1u8)]).unwrap();
+        set_promise_results(vec![PromiseResult::Successful(body)]);
+
+        assert!(aml.assert_risk_promise_result(address.clone()));
+        assert!(aml.cached_verdict(&address, u64::MAX).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_ADDRESS_RISK_TOO_HIGH")]
+    fn assert_risk_promise_result_panics_on_high_risk() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+        let body = near_sdk::serde_json::to_vec(&vec![(Category::Scam, MAX_RISK_LEVEL)]).unwrap();
+        set_promise_results(vec![PromiseResult::Successful(body)]);
+
+        aml.assert_risk_promise_result(address);
+    }
+
+    #[test]
+    fn whitelist_takes_priority_over_blacklist() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+
+        aml.add_to_blacklist(address.clone());
+        aml.add_to_whitelist(address.clone());
+
+        assert_eq!(aml.local_verdict(&address), Some(true));
+    }
+
+    #[test]
+    fn blacklist_applies_when_not_whitelisted() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+
+        aml.add_to_blacklist(address.clone());
+
+        assert_eq!(aml.local_verdict(&address), Some(false));
+    }
+
+    #[test]
+    fn no_override_falls_through_to_query() {
+        let aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        let address = accounts(1);
+
+        assert_eq!(aml.local_verdict(&address), None);
+    }
+}
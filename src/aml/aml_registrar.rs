@@ -1,11 +1,13 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::UnorderedMap;
-use near_sdk::{AccountId, BorshStorageKey};
+use near_sdk::collections::{UnorderedMap, UnorderedSet, Vector};
+use near_sdk::{env, AccountId, BorshStorageKey};
 use serde::{Deserialize, Serialize};
 
-use super::CategoryRisk;
+use super::{CategoryRisk, HapiError};
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, Debug,
+)]
 #[serde(crate = "near_sdk::serde")]
 pub enum Category {
     // for all unspecified categories
@@ -57,27 +59,119 @@ pub const MAX_RISK_LEVEL: RiskScore = 10;
 #[derive(BorshStorageKey, BorshSerialize)]
 enum StorageKey {
     AmlCategory,
+    AmlBlacklist,
+    AmlWhitelist,
+    AmlCache,
+    AmlProviders,
+}
+
+/// How a verdict is reached when more than one HAPI-compatible provider is configured.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum ProviderStrategy {
+    /// Try providers in priority order, falling through to the next one if
+    /// a provider's cross-contract call errors.
+    FirstAvailable,
+    /// Accept a category/risk only if at least `n` providers agree it
+    /// exceeds its accepted threshold. An address is rejected outright when
+    /// fewer than `n` providers return a response.
+    Quorum { n: u8 },
+}
+
+/// How a returned [`CategoryRisk`] vector is reduced to an allow/reject decision.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum AggregatePolicy {
+    /// Reject as soon as any single category exceeds its accepted risk score.
+    /// This is the default and matches the original per-category behavior.
+    AnyExceeds,
+    /// Reject when the highest reported risk score exceeds the accepted risk
+    /// score configured for [`Category::All`].
+    MaxOf,
+    /// Multiply each returned category's risk score by its configured
+    /// weight (default `1` when unweighted), sum them, and reject when the
+    /// total passes `limit`. `weights` is embedded directly in the enum
+    /// rather than backed by a top-level persistent collection, since a
+    /// fixed storage prefix would be shared (and silently accumulate
+    /// entries) across every policy switch.
+    WeightedSum {
+        weights: Vec<(Category, u8)>,
+        limit: u32,
+    },
+}
+
+impl AggregatePolicy {
+    /// Builds a [`AggregatePolicy::WeightedSum`] policy from `weights`, rejecting
+    /// when the sum of `risk_score * weight` across all returned categories
+    /// passes `limit`.
+    pub fn weighted_sum(weights: &[(Category, u8)], limit: u32) -> Self {
+        AggregatePolicy::WeightedSum {
+            weights: weights.to_vec(),
+            limit,
+        }
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct AML {
-    pub(crate) account_id: AccountId,
+    /// HAPI-compatible provider accounts in priority order; index `0` is
+    /// the primary provider consulted by [`AmlManager::update_account_id`]
+    /// and [`AmlManager::get_aml`].
+    pub(crate) providers: Vector<AccountId>,
+    pub(crate) strategy: ProviderStrategy,
     pub(crate) aml_conditions: UnorderedMap<Category, RiskScore>,
+    pub(crate) blacklist: UnorderedSet<AccountId>,
+    pub(crate) whitelist: UnorderedSet<AccountId>,
+    pub(crate) verdict_cache: UnorderedMap<AccountId, (Vec<CategoryRisk>, u64)>,
+    pub(crate) policy: AggregatePolicy,
 }
 
 pub trait AmlManager {
-    fn get_aml(&self) -> (&AccountId, Vec<CategoryRisk>);
+    fn get_aml(&self) -> (AccountId, Vec<CategoryRisk>);
 
     fn get_aml_conditions(&self) -> &UnorderedMap<Category, RiskScore>;
 
     fn update_account_id(&mut self, aml_account_id: AccountId);
 
+    fn get_providers(&self) -> Vec<AccountId>;
+    fn add_provider(&mut self, account_id: AccountId);
+    fn remove_provider(&mut self, account_id: AccountId);
+
+    fn try_add_provider(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+    fn try_remove_provider(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+
+    fn get_strategy(&self) -> &ProviderStrategy;
+    fn set_strategy(&mut self, strategy: ProviderStrategy);
+
     fn update_category(&mut self, category: Category, accepted_risk_score: RiskScore);
     fn remove_category(&mut self, category: Category);
+
+    fn try_update_category(
+        &mut self,
+        category: Category,
+        accepted_risk_score: RiskScore,
+    ) -> Result<(), HapiError>;
+    fn try_remove_category(&mut self, category: Category) -> Result<(), HapiError>;
+
+    fn is_blacklisted(&self, account_id: &AccountId) -> bool;
+    fn add_to_blacklist(&mut self, account_id: AccountId);
+    fn remove_from_blacklist(&mut self, account_id: AccountId);
+
+    fn try_add_to_blacklist(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+    fn try_remove_from_blacklist(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+
+    fn is_whitelisted(&self, account_id: &AccountId) -> bool;
+    fn add_to_whitelist(&mut self, account_id: AccountId);
+    fn remove_from_whitelist(&mut self, account_id: AccountId);
+
+    fn try_add_to_whitelist(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+    fn try_remove_from_whitelist(&mut self, account_id: AccountId) -> Result<(), HapiError>;
+
+    fn get_policy(&self) -> &AggregatePolicy;
+    fn set_policy(&mut self, policy: AggregatePolicy);
 }
 
 impl AmlManager for AML {
-    /// Returns the aml accountId and vector of added categories with accepted risk levels.
+    /// Returns the primary aml provider accountId and vector of added
+    /// categories with accepted risk levels.
     ///
     /// # Examples
     ///
@@ -90,9 +184,11 @@ impl AmlManager for AML {
     /// let aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
     /// println!("{:?}", aml.get_aml());
     /// ```
-    fn get_aml(&self) -> (&AccountId, Vec<(Category, RiskScore)>) {
+    fn get_aml(&self) -> (AccountId, Vec<(Category, RiskScore)>) {
         (
-            &self.account_id,
+            self.providers
+                .get(0)
+                .unwrap_or_else(|| env::panic_str("ERR_NO_AML_PROVIDER_CONFIGURED")),
             self.aml_conditions
                 .iter()
                 .map(|(id, acc)| (id, acc))
@@ -117,7 +213,8 @@ impl AmlManager for AML {
         &self.aml_conditions
     }
 
-    /// Updates account id of aml service.
+    /// Replaces the primary (highest-priority) aml provider, leaving any
+    /// additional providers added through [`AmlManager::add_provider`] untouched.
     ///
     /// # Examples
     ///
@@ -127,16 +224,95 @@ impl AmlManager for AML {
     ///
     /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
     ///
-    /// let aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    /// let mut aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
     ///
     /// let new_aml_account: AccountId = AccountId::new_unchecked("new_aml".to_string());
     /// aml.update_account_id(new_aml_account.clone());
     ///
     /// let (account_id, _) = aml.get_aml();
-    /// assert_eq!(*account_id, new_aml_account);
+    /// assert_eq!(account_id, new_aml_account);
     /// ```
     fn update_account_id(&mut self, account_id: AccountId) {
-        self.account_id = account_id;
+        if self.providers.is_empty() {
+            self.providers.push(&account_id);
+        } else {
+            self.providers.replace(0, &account_id);
+        }
+    }
+
+    /// Returns every configured provider in priority order.
+    fn get_providers(&self) -> Vec<AccountId> {
+        self.providers.to_vec()
+    }
+
+    /// Appends `account_id` as the lowest-priority provider.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let mut aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// let backup: AccountId = AccountId::new_unchecked("aml-backup".to_string());
+    /// aml.add_provider(backup.clone());
+    ///
+    /// assert!(aml.get_providers().contains(&backup));
+    /// ```
+    fn add_provider(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_add_provider(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Removes `account_id` from the provider list.
+    fn remove_provider(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_remove_provider(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Fallible variant of [`AmlManager::add_provider`].
+    fn try_add_provider(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        let already_added = (0..self.providers.len())
+            .any(|i| self.providers.get(i).as_ref() == Some(&account_id));
+        if already_added {
+            return Err(HapiError::ProviderAlreadyAdded(account_id));
+        }
+        self.providers.push(&account_id);
+        Ok(())
+    }
+
+    /// Fallible variant of [`AmlManager::remove_provider`].
+    fn try_remove_provider(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        let remaining: Vec<AccountId> = self.providers.to_vec();
+        let remaining: Vec<AccountId> = remaining
+            .into_iter()
+            .filter(|provider| provider != &account_id)
+            .collect();
+        if remaining.len() == self.providers.len() as usize {
+            return Err(HapiError::ProviderNotConfigured(account_id));
+        }
+        if remaining.is_empty() {
+            return Err(HapiError::CannotRemoveLastProvider);
+        }
+        self.providers.clear();
+        for provider in &remaining {
+            self.providers.push(provider);
+        }
+        Ok(())
+    }
+
+    /// Returns the strategy used to resolve a verdict across multiple providers.
+    fn get_strategy(&self) -> &ProviderStrategy {
+        &self.strategy
+    }
+
+    /// Replaces the multi-provider resolution strategy.
+    fn set_strategy(&mut self, strategy: ProviderStrategy) {
+        self.strategy = strategy;
     }
 
     /// Updates or add category with accepted risk score to aml conditions.
@@ -156,12 +332,9 @@ impl AmlManager for AML {
     /// assert_eq!(aml.get_aml_conditions().get(&Category::Scam).unwrap(), 6);
     /// ```
     fn update_category(&mut self, category: Category, accepted_risk_score: RiskScore) {
-        assert!(
-            accepted_risk_score <= MAX_RISK_LEVEL,
-            "ERR_RISK_SCORE_IS_INVALID"
-        );
-        assert!(accepted_risk_score > 0, "ERR_RISK_SCORE_IS_INVALID");
-        self.aml_conditions.insert(&category, &accepted_risk_score);
+        if let Err(err) = self.try_update_category(category, accepted_risk_score) {
+            env::panic_str(&err.to_string());
+        }
     }
 
     /// Removes category from aml conditions.
@@ -182,18 +355,232 @@ impl AmlManager for AML {
     /// assert!(aml.get_aml_conditions().get(&Category::Scam).is_none());
     /// ```
     fn remove_category(&mut self, category: Category) {
-        assert!(category != Category::All);
+        if let Err(err) = self.try_remove_category(category) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Fallible variant of [`AmlManager::update_category`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let mut aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// assert_eq!(aml.try_update_category(Category::Scam, 0), Err(HapiError::RiskScoreZero));
+    /// ```
+    fn try_update_category(
+        &mut self,
+        category: Category,
+        accepted_risk_score: RiskScore,
+    ) -> Result<(), HapiError> {
+        if accepted_risk_score > MAX_RISK_LEVEL {
+            return Err(HapiError::RiskScoreOutOfRange {
+                got: accepted_risk_score,
+                max: MAX_RISK_LEVEL,
+            });
+        }
+        if accepted_risk_score == 0 {
+            return Err(HapiError::RiskScoreZero);
+        }
+        self.aml_conditions.insert(&category, &accepted_risk_score);
+        Ok(())
+    }
+
+    /// Fallible variant of [`AmlManager::remove_category`].
+    fn try_remove_category(&mut self, category: Category) -> Result<(), HapiError> {
+        if category == Category::All {
+            return Err(HapiError::CannotRemoveAllCategory);
+        }
+        if self.aml_conditions.get(&category).is_none() {
+            return Err(HapiError::CategoryNotConfigured(category));
+        }
         self.aml_conditions.remove(&category);
+        Ok(())
+    }
+
+    /// Returns whether `account_id` was hard-blocked through [`AmlManager::add_to_blacklist`].
+    fn is_blacklisted(&self, account_id: &AccountId) -> bool {
+        self.blacklist.contains(account_id)
+    }
+
+    /// Hard-blocks `account_id`, rejecting it without waiting on the HAPI service.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let mut aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// let bad_actor: AccountId = AccountId::new_unchecked("bad_actor.near".to_string());
+    /// aml.add_to_blacklist(bad_actor.clone());
+    ///
+    /// assert!(aml.is_blacklisted(&bad_actor));
+    /// ```
+    fn add_to_blacklist(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_add_to_blacklist(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Removes a previously hard-blocked `account_id` from the blacklist.
+    fn remove_from_blacklist(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_remove_from_blacklist(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Fallible variant of [`AmlManager::add_to_blacklist`].
+    fn try_add_to_blacklist(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        if self.blacklist.contains(&account_id) {
+            return Err(HapiError::UserAlreadyBlacklisted(account_id));
+        }
+        self.blacklist.insert(&account_id);
+        Ok(())
+    }
+
+    /// Fallible variant of [`AmlManager::remove_from_blacklist`].
+    fn try_remove_from_blacklist(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        if !self.blacklist.contains(&account_id) {
+            return Err(HapiError::UserNotBlacklisted(account_id));
+        }
+        self.blacklist.remove(&account_id);
+        Ok(())
+    }
+
+    /// Returns whether `account_id` was hard-passed through [`AmlManager::add_to_whitelist`].
+    fn is_whitelisted(&self, account_id: &AccountId) -> bool {
+        self.whitelist.contains(account_id)
+    }
+
+    /// Hard-passes `account_id`, always allowing it without waiting on the HAPI service.
+    fn add_to_whitelist(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_add_to_whitelist(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Removes a previously hard-passed `account_id` from the whitelist.
+    fn remove_from_whitelist(&mut self, account_id: AccountId) {
+        if let Err(err) = self.try_remove_from_whitelist(account_id) {
+            env::panic_str(&err.to_string());
+        }
+    }
+
+    /// Fallible variant of [`AmlManager::add_to_whitelist`].
+    fn try_add_to_whitelist(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        if self.whitelist.contains(&account_id) {
+            return Err(HapiError::UserAlreadyWhitelisted(account_id));
+        }
+        self.whitelist.insert(&account_id);
+        Ok(())
+    }
+
+    /// Fallible variant of [`AmlManager::remove_from_whitelist`].
+    fn try_remove_from_whitelist(&mut self, account_id: AccountId) -> Result<(), HapiError> {
+        if !self.whitelist.contains(&account_id) {
+            return Err(HapiError::UserNotWhitelisted(account_id));
+        }
+        self.whitelist.remove(&account_id);
+        Ok(())
+    }
+
+    /// Returns the policy used to reduce a returned risk vector to a verdict.
+    fn get_policy(&self) -> &AggregatePolicy {
+        &self.policy
+    }
+
+    /// Replaces the aggregate risk policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use near_sdk::AccountId;
+    /// use hapi_near_connector::aml::*;
+    ///
+    /// let aml_account: AccountId = AccountId::new_unchecked("aml".to_string());
+    /// let mut aml: AML = AML::new(aml_account, MAX_RISK_LEVEL / 2);
+    ///
+    /// aml.set_policy(AggregatePolicy::MaxOf);
+    /// assert!(matches!(aml.get_policy(), AggregatePolicy::MaxOf));
+    /// ```
+    fn set_policy(&mut self, policy: AggregatePolicy) {
+        self.policy = policy;
     }
 }
 
 impl AML {
     pub fn new(account_id: AccountId, accepted_risk_score: RiskScore) -> AML {
+        match Self::try_new(account_id, accepted_risk_score) {
+            Ok(aml) => aml,
+            Err(err) => env::panic_str(&err.to_string()),
+        }
+    }
+
+    /// Fallible variant of [`AML::new`].
+    pub fn try_new(account_id: AccountId, accepted_risk_score: RiskScore) -> Result<AML, HapiError> {
+        let mut providers = Vector::new(StorageKey::AmlProviders);
+        providers.push(&account_id);
         let mut aml = Self {
-            account_id,
+            providers,
+            strategy: ProviderStrategy::FirstAvailable,
             aml_conditions: UnorderedMap::new(StorageKey::AmlCategory),
+            blacklist: UnorderedSet::new(StorageKey::AmlBlacklist),
+            whitelist: UnorderedSet::new(StorageKey::AmlWhitelist),
+            verdict_cache: UnorderedMap::new(StorageKey::AmlCache),
+            policy: AggregatePolicy::AnyExceeds,
         };
-        aml.update_category(Category::All, accepted_risk_score);
-        aml
+        aml.try_update_category(Category::All, accepted_risk_score)?;
+        Ok(aml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::accounts;
+
+    use super::*;
+
+    #[test]
+    fn try_remove_category_errors_when_not_configured() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        assert_eq!(
+            aml.try_remove_category(Category::Scam),
+            Err(HapiError::CategoryNotConfigured(Category::Scam))
+        );
+    }
+
+    #[test]
+    fn try_remove_category_cannot_remove_all() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        assert_eq!(
+            aml.try_remove_category(Category::All),
+            Err(HapiError::CannotRemoveAllCategory)
+        );
+    }
+
+    #[test]
+    fn try_remove_provider_cannot_remove_last_provider() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        assert_eq!(
+            aml.try_remove_provider(accounts(0)),
+            Err(HapiError::CannotRemoveLastProvider)
+        );
+        assert_eq!(aml.get_providers(), vec![accounts(0)]);
+    }
+
+    #[test]
+    fn try_remove_provider_allows_removal_above_last() {
+        let mut aml = AML::new(accounts(0), MAX_RISK_LEVEL / 2);
+        aml.try_add_provider(accounts(1)).unwrap();
+        assert_eq!(aml.try_remove_provider(accounts(0)), Ok(()));
+        assert_eq!(aml.get_providers(), vec![accounts(1)]);
     }
 }
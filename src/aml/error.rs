@@ -0,0 +1,81 @@
+use std::fmt;
+
+use near_sdk::AccountId;
+
+use super::{Category, RiskScore};
+
+/// Structured failure modes for the fallible `try_*` variants of [`crate::aml::AmlManager`].
+///
+/// The panicking methods (`update_category`, `remove_category`, `new`) wrap
+/// these same errors with `env::panic_str` so host contracts that don't
+/// need to recover can keep calling them directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HapiError {
+    /// The accepted risk score is greater than [`crate::aml::MAX_RISK_LEVEL`].
+    RiskScoreOutOfRange { got: RiskScore, max: RiskScore },
+    /// The accepted risk score was zero, which would reject everything.
+    RiskScoreZero,
+    /// `Category::All` is the fallback condition and can't be removed.
+    CannotRemoveAllCategory,
+    /// The category has no accepted risk score configured.
+    CategoryNotConfigured(Category),
+    /// The account is already on the blacklist.
+    UserAlreadyBlacklisted(AccountId),
+    /// The account is not on the blacklist.
+    UserNotBlacklisted(AccountId),
+    /// The account is already on the whitelist.
+    UserAlreadyWhitelisted(AccountId),
+    /// The account is not on the whitelist.
+    UserNotWhitelisted(AccountId),
+    /// The account is already a configured provider.
+    ProviderAlreadyAdded(AccountId),
+    /// The account is not a configured provider.
+    ProviderNotConfigured(AccountId),
+    /// The provider list can't be emptied; at least one must remain
+    /// configured for `get_aml`/`query_risk` to have something to call.
+    CannotRemoveLastProvider,
+}
+
+impl fmt::Display for HapiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HapiError::RiskScoreOutOfRange { got, max } => write!(
+                f,
+                "risk score {} is out of range, must not exceed {}",
+                got, max
+            ),
+            HapiError::RiskScoreZero => {
+                write!(f, "risk score must be greater than zero")
+            }
+            HapiError::CannotRemoveAllCategory => {
+                write!(f, "Category::All cannot be removed from aml conditions")
+            }
+            HapiError::CategoryNotConfigured(category) => {
+                write!(f, "category {:?} is not configured in aml conditions", category)
+            }
+            HapiError::UserAlreadyBlacklisted(account_id) => {
+                write!(f, "account {} is already blacklisted", account_id)
+            }
+            HapiError::UserNotBlacklisted(account_id) => {
+                write!(f, "account {} is not blacklisted", account_id)
+            }
+            HapiError::UserAlreadyWhitelisted(account_id) => {
+                write!(f, "account {} is already whitelisted", account_id)
+            }
+            HapiError::UserNotWhitelisted(account_id) => {
+                write!(f, "account {} is not whitelisted", account_id)
+            }
+            HapiError::ProviderAlreadyAdded(account_id) => {
+                write!(f, "provider {} is already configured", account_id)
+            }
+            HapiError::ProviderNotConfigured(account_id) => {
+                write!(f, "provider {} is not configured", account_id)
+            }
+            HapiError::CannotRemoveLastProvider => {
+                write!(f, "cannot remove the last remaining aml provider")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HapiError {}
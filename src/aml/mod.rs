@@ -0,0 +1,17 @@
+mod aml_cache;
+mod aml_policy;
+mod aml_provider;
+mod aml_query;
+mod aml_registrar;
+mod error;
+
+pub use aml_cache::*;
+pub use aml_policy::*;
+pub use aml_provider::*;
+pub use aml_query::*;
+pub use aml_registrar::*;
+pub use error::*;
+
+/// A single category verdict as reported by a HAPI service account, e.g.
+/// `(Category::Gambling, 5)`.
+pub type CategoryRisk = (Category, RiskScore);